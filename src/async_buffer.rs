@@ -1,7 +1,9 @@
 use crate::async_device::AsyncDevice;
 use std::{
+    marker::PhantomData,
     mem::ManuallyDrop,
     ops::{Deref, DerefMut, RangeBounds},
+    sync::Arc,
 };
 use wgpu::BufferAddress;
 
@@ -13,12 +15,23 @@ where
     Self: wgpu::WasmNotSend,
 {
     device: AsyncDevice,
-    buffer: wgpu::Buffer,
+    buffer: Arc<wgpu::Buffer>,
 }
 
 impl AsyncBuffer {
     /// Wraps a buffer to allow for mapping using `async`.
     pub fn wrap(device: AsyncDevice, buffer: wgpu::Buffer) -> Self {
+        Self::wrap_shared(device, Arc::new(buffer))
+    }
+
+    /// Wraps an already-shared buffer, the same as [`Self::wrap`] but for a buffer that is
+    /// also owned elsewhere.
+    ///
+    /// Sharing ownership this way is what lets [`Self::map_async_owned`] and
+    /// [`Self::map_async_mut_owned`] return views that own the buffer themselves, so they can
+    /// be moved into a spawned task or returned up the stack rather than staying tied to the
+    /// borrow of an `&AsyncBuffer`.
+    pub fn wrap_shared(device: AsyncDevice, buffer: Arc<wgpu::Buffer>) -> Self {
         Self { device, buffer }
     }
 
@@ -34,6 +47,11 @@ impl AsyncBuffer {
     }
 
     /// An awaitable version of [`wgpu::Buffer::map_async`] with [`wgpu::MapMode::Read`].
+    ///
+    /// The returned future only resolves once the device is polled, so either drive this
+    /// buffer's [`AsyncDevice`] with [`crate::async_device::AsyncDevice::spawn_poller`] or
+    /// [`crate::async_device::block_on`], or ensure some other part of the program is polling
+    /// it (e.g. via `wgpu::Instance::poll_all`).
     pub async fn map_async<S: RangeBounds<wgpu::BufferAddress>>(
         &self,
         bounds: S,
@@ -43,6 +61,9 @@ impl AsyncBuffer {
     }
 
     /// An awaitable version of [`wgpu::Buffer::map_async`] with [`wgpu::MapMode::Write`].
+    ///
+    /// See [`Self::map_async`] for the requirement that the device be polled for this to
+    /// resolve.
     pub async fn map_async_mut<S: RangeBounds<wgpu::BufferAddress>>(
         &self,
         bounds: S,
@@ -50,6 +71,77 @@ impl AsyncBuffer {
         let slice = self.slice(bounds);
         slice.map_async_mut().await
     }
+
+    /// Like [`Self::map_async`], but exposes the mapped range as `&[T]` rather than raw
+    /// bytes, using [`bytemuck`] to validate the cast.
+    ///
+    /// See [`Self::map_async`] for the requirement that the device be polled for this to
+    /// resolve.
+    pub async fn map_async_typed<T: bytemuck::Pod, S: RangeBounds<wgpu::BufferAddress>>(
+        &self,
+        bounds: S,
+    ) -> Result<TypedAsyncBufferView<'_, T>, MapTypedError> {
+        let view = self.map_async(bounds).await?;
+        TypedAsyncBufferView::new(view)
+    }
+
+    /// Like [`Self::map_async_mut`], but exposes the mapped range as `&mut [T]` rather than
+    /// raw bytes, using [`bytemuck`] to validate the cast.
+    ///
+    /// See [`Self::map_async`] for the requirement that the device be polled for this to
+    /// resolve.
+    pub async fn map_async_mut_typed<T: bytemuck::Pod, S: RangeBounds<wgpu::BufferAddress>>(
+        &self,
+        bounds: S,
+    ) -> Result<TypedAsyncBufferViewMut<'_, T>, MapTypedError> {
+        let view = self.map_async_mut(bounds).await?;
+        TypedAsyncBufferViewMut::new(view)
+    }
+
+    /// Like [`Self::map_async`], but the returned view owns a clone of this buffer's `Arc`
+    /// rather than borrowing it, so it is `'static` and can cross `await` points and task
+    /// boundaries (e.g. be sent over a channel).
+    ///
+    /// While the returned view is alive, it holds a second owner of this buffer's `Arc`, so
+    /// any `&mut self` access to this `AsyncBuffer` (including through [`DerefMut`]/[`AsMut`])
+    /// will panic until the view is dropped.
+    ///
+    /// See [`Self::map_async`] for the requirement that the device be polled for this to
+    /// resolve.
+    pub async fn map_async_owned<S: RangeBounds<wgpu::BufferAddress>>(
+        &self,
+        bounds: S,
+    ) -> Result<OwnedAsyncBufferView, wgpu::BufferAsyncError> {
+        let buffer_slice = self.buffer.slice(bounds);
+        self.device
+            .do_async(|callback| buffer_slice.map_async(wgpu::MapMode::Read, callback))
+            .await?;
+        Ok(OwnedAsyncBufferView::new(self.buffer.clone(), &buffer_slice))
+    }
+
+    /// Like [`Self::map_async_mut`], but the returned view owns a clone of this buffer's `Arc`
+    /// rather than borrowing it, so it is `'static` and can cross `await` points and task
+    /// boundaries (e.g. be sent over a channel).
+    ///
+    /// While the returned view is alive, it holds a second owner of this buffer's `Arc`, so
+    /// any `&mut self` access to this `AsyncBuffer` (including through [`DerefMut`]/[`AsMut`])
+    /// will panic until the view is dropped.
+    ///
+    /// See [`Self::map_async`] for the requirement that the device be polled for this to
+    /// resolve.
+    pub async fn map_async_mut_owned<S: RangeBounds<wgpu::BufferAddress>>(
+        &self,
+        bounds: S,
+    ) -> Result<OwnedAsyncBufferViewMut, wgpu::BufferAsyncError> {
+        let buffer_slice = self.buffer.slice(bounds);
+        self.device
+            .do_async(|callback| buffer_slice.map_async(wgpu::MapMode::Write, callback))
+            .await?;
+        Ok(OwnedAsyncBufferViewMut::new(
+            self.buffer.clone(),
+            &buffer_slice,
+        ))
+    }
 }
 impl Deref for AsyncBuffer {
     type Target = wgpu::Buffer;
@@ -60,7 +152,13 @@ impl Deref for AsyncBuffer {
 }
 impl DerefMut for AsyncBuffer {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.buffer
+        Arc::get_mut(&mut self.buffer).expect(
+            "cannot mutably access an AsyncBuffer's wgpu::Buffer while another owner of it is \
+             still alive -- this buffer was either constructed via `AsyncBuffer::wrap_shared` \
+             with an `Arc` that has other owners, or an `OwnedAsyncBufferView`/\
+             `OwnedAsyncBufferViewMut` from `map_async_owned`/`map_async_mut_owned` is still in \
+             scope",
+        )
     }
 }
 impl<T> AsRef<T> for AsyncBuffer
@@ -101,6 +199,12 @@ impl<'a> AsyncBufferView<'a> {
             buffer_view: ManuallyDrop::new(buffer_view),
         }
     }
+
+    /// Casts the mapped bytes to a typed slice with [`bytemuck::try_cast_slice`], failing if
+    /// the mapped range's length or alignment is incompatible with `T`.
+    pub fn as_slice<T: bytemuck::Pod>(&self) -> Result<&[T], bytemuck::PodCastError> {
+        bytemuck::try_cast_slice(&self.buffer_view)
+    }
 }
 
 impl<'a> Drop for AsyncBufferView<'a> {
@@ -140,6 +244,19 @@ impl<'a> AsyncBufferViewMut<'a> {
             buffer_view: ManuallyDrop::new(buffer_view),
         }
     }
+
+    /// Casts the mapped bytes to a typed slice with [`bytemuck::try_cast_slice`], failing if
+    /// the mapped range's length or alignment is incompatible with `T`.
+    pub fn as_slice<T: bytemuck::Pod>(&self) -> Result<&[T], bytemuck::PodCastError> {
+        bytemuck::try_cast_slice(&self.buffer_view)
+    }
+
+    /// Casts the mapped bytes to a mutable typed slice with
+    /// [`bytemuck::try_cast_slice_mut`], failing if the mapped range's length or alignment
+    /// is incompatible with `T`.
+    pub fn as_mut_slice<T: bytemuck::Pod>(&mut self) -> Result<&mut [T], bytemuck::PodCastError> {
+        bytemuck::try_cast_slice_mut(&mut self.buffer_view)
+    }
 }
 
 impl<'a> Drop for AsyncBufferViewMut<'a> {
@@ -153,6 +270,92 @@ impl<'a> Drop for AsyncBufferViewMut<'a> {
     }
 }
 
+/// Like [`AsyncBufferView`], but owns a clone of the mapped buffer's `Arc` instead of
+/// borrowing it, so it is `'static` and can be moved into a spawned task or returned up the
+/// stack. Returned by [`AsyncBuffer::map_async_owned`].
+pub struct OwnedAsyncBufferView {
+    buffer: Arc<wgpu::Buffer>,
+    buffer_view: ManuallyDrop<wgpu::BufferView<'static>>,
+}
+
+impl Deref for OwnedAsyncBufferView {
+    type Target = wgpu::BufferView<'static>;
+    fn deref(&self) -> &Self::Target {
+        &self.buffer_view
+    }
+}
+
+impl OwnedAsyncBufferView {
+    fn new(buffer: Arc<wgpu::Buffer>, buffer_slice: &wgpu::BufferSlice<'_>) -> Self {
+        let buffer_view = buffer_slice.get_mapped_range();
+        // SAFETY: `buffer_view` borrows from `buffer_slice`, which borrows from `buffer`.
+        // `buffer` is kept alive for as long as `Self` exists (via the `Arc` stored
+        // alongside the view), and the extended-lifetime view is never exposed past `Self`'s
+        // own lifetime, so this cannot outlive the buffer it references.
+        let buffer_view: wgpu::BufferView<'static> = unsafe { std::mem::transmute(buffer_view) };
+        Self {
+            buffer,
+            buffer_view: ManuallyDrop::new(buffer_view),
+        }
+    }
+}
+
+impl Drop for OwnedAsyncBufferView {
+    fn drop(&mut self) {
+        // `buffer_view` is never used after this point.
+        unsafe {
+            ManuallyDrop::drop(&mut self.buffer_view);
+        }
+
+        self.buffer.unmap();
+    }
+}
+
+/// Like [`AsyncBufferViewMut`], but owns a clone of the mapped buffer's `Arc` instead of
+/// borrowing it, so it is `'static` and can be moved into a spawned task or returned up the
+/// stack. Returned by [`AsyncBuffer::map_async_mut_owned`].
+pub struct OwnedAsyncBufferViewMut {
+    buffer: Arc<wgpu::Buffer>,
+    buffer_view: ManuallyDrop<wgpu::BufferViewMut<'static>>,
+}
+
+impl Deref for OwnedAsyncBufferViewMut {
+    type Target = wgpu::BufferViewMut<'static>;
+    fn deref(&self) -> &Self::Target {
+        &self.buffer_view
+    }
+}
+
+impl DerefMut for OwnedAsyncBufferViewMut {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer_view
+    }
+}
+
+impl OwnedAsyncBufferViewMut {
+    pub(crate) fn new(buffer: Arc<wgpu::Buffer>, buffer_slice: &wgpu::BufferSlice<'_>) -> Self {
+        let buffer_view = buffer_slice.get_mapped_range_mut();
+        // SAFETY: see `OwnedAsyncBufferView::new`.
+        let buffer_view: wgpu::BufferViewMut<'static> =
+            unsafe { std::mem::transmute(buffer_view) };
+        Self {
+            buffer,
+            buffer_view: ManuallyDrop::new(buffer_view),
+        }
+    }
+}
+
+impl Drop for OwnedAsyncBufferViewMut {
+    fn drop(&mut self) {
+        // `buffer_view` is never used after this point.
+        unsafe {
+            ManuallyDrop::drop(&mut self.buffer_view);
+        }
+
+        self.buffer.unmap();
+    }
+}
+
 /// A smart-pointer wrapper around a [`wgpu::BufferSlice`], offering a `map_async` method than can be `await`ed.
 #[derive(Debug)]
 pub struct AsyncBufferSlice<'a>
@@ -172,6 +375,11 @@ impl<'a> AsyncBufferSlice<'a> {
     }
 
     /// An awaitable version of [`wgpu::BufferSlice::map_async`] with [`wgpu::MapMode::Read`].
+    ///
+    /// The returned future only resolves once the device is polled, so either drive this
+    /// slice's [`AsyncDevice`] with [`crate::async_device::AsyncDevice::spawn_poller`] or
+    /// [`crate::async_device::block_on`], or ensure some other part of the program is polling
+    /// it (e.g. via `wgpu::Instance::poll_all`).
     pub async fn map_async(self) -> Result<AsyncBufferView<'a>, wgpu::BufferAsyncError> {
         self.device
             .do_async(|callback| self.buffer_slice.map_async(wgpu::MapMode::Read, callback))
@@ -180,12 +388,64 @@ impl<'a> AsyncBufferSlice<'a> {
     }
 
     /// An awaitable version of [`wgpu::BufferSlice::map_async`] with [`wgpu::MapMode::Write`].
+    ///
+    /// See [`Self::map_async`] for the requirement that the device be polled for this to
+    /// resolve.
     pub async fn map_async_mut(self) -> Result<AsyncBufferViewMut<'a>, wgpu::BufferAsyncError> {
         self.device
             .do_async(|callback| self.buffer_slice.map_async(wgpu::MapMode::Write, callback))
             .await?;
         Ok(AsyncBufferViewMut::new(&self.buffer_slice))
     }
+
+    /// Like [`Self::map_async`], but waits precisely for `submission_index` to finish rather
+    /// than relying on some other part of the program to poll the device. Use this when you
+    /// know which [`wgpu::SubmissionIndex`] the mapped data depends on (e.g. the submission
+    /// that issued a `copy_buffer_to_buffer` into this buffer) to avoid over-polling.
+    ///
+    /// `AsyncDevice::do_async` registers the map with wgpu synchronously, before this returns
+    /// the in-progress future, so it's safe to poll for `submission_index` between that call
+    /// and awaiting it below; wgpu already knows about the pending map by then.
+    ///
+    /// # Blocking
+    ///
+    /// [`wgpu::Maintain::WaitForSubmissionIndex`] blocks the calling thread until that
+    /// submission finishes, and that wait happens synchronously inside this `async fn`, on
+    /// whatever thread first polls it. Only drive this future from a thread you're willing to
+    /// block for that long, e.g. with [`crate::async_device::block_on`] or a thread dedicated
+    /// to wgpu work. Never `.await` it from a task on a cooperative multitasking executor
+    /// (tokio, async-std, ...) — it would stall the worker thread and could starve or deadlock
+    /// other tasks sharing it.
+    pub async fn map_async_with_index(
+        self,
+        submission_index: wgpu::SubmissionIndex,
+    ) -> Result<AsyncBufferView<'a>, wgpu::BufferAsyncError> {
+        let device = self.device.clone();
+        let mapped = self
+            .device
+            .do_async(|callback| self.buffer_slice.map_async(wgpu::MapMode::Read, callback));
+        device.poll(wgpu::Maintain::WaitForSubmissionIndex(submission_index));
+        mapped.await?;
+        Ok(AsyncBufferView::new(&self.buffer_slice))
+    }
+
+    /// Like [`Self::map_async_mut`], but waits precisely for `submission_index` to finish
+    /// rather than relying on some other part of the program to poll the device.
+    ///
+    /// See [`Self::map_async_with_index`]'s "Blocking" section: the same constraints apply
+    /// here.
+    pub async fn map_async_mut_with_index(
+        self,
+        submission_index: wgpu::SubmissionIndex,
+    ) -> Result<AsyncBufferViewMut<'a>, wgpu::BufferAsyncError> {
+        let device = self.device.clone();
+        let mapped = self
+            .device
+            .do_async(|callback| self.buffer_slice.map_async(wgpu::MapMode::Write, callback));
+        device.poll(wgpu::Maintain::WaitForSubmissionIndex(submission_index));
+        mapped.await?;
+        Ok(AsyncBufferViewMut::new(&self.buffer_slice))
+    }
 }
 impl<'a> Deref for AsyncBufferSlice<'a> {
     type Target = wgpu::BufferSlice<'a>;
@@ -216,3 +476,107 @@ where
         self.deref_mut().as_mut()
     }
 }
+
+/// The error returned by [`AsyncBuffer::map_async_typed`] and
+/// [`AsyncBuffer::map_async_mut_typed`].
+#[derive(Debug)]
+pub enum MapTypedError {
+    /// The map operation itself failed.
+    Map(wgpu::BufferAsyncError),
+    /// The mapped range could not be cast to the requested type.
+    Cast(bytemuck::PodCastError),
+}
+
+impl From<wgpu::BufferAsyncError> for MapTypedError {
+    fn from(error: wgpu::BufferAsyncError) -> Self {
+        Self::Map(error)
+    }
+}
+
+impl From<bytemuck::PodCastError> for MapTypedError {
+    fn from(error: bytemuck::PodCastError) -> Self {
+        Self::Cast(error)
+    }
+}
+
+impl std::fmt::Display for MapTypedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Map(error) => error.fmt(f),
+            Self::Cast(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for MapTypedError {}
+
+/// A typed view over a mapped buffer range, returned by [`AsyncBuffer::map_async_typed`].
+///
+/// Derefs to `[T]` rather than `[u8]`, so compute readback code can index or iterate the
+/// mapped range without a manual `bytemuck` cast at every use site.
+pub struct TypedAsyncBufferView<'a, T> {
+    view: AsyncBufferView<'a>,
+    _element: PhantomData<T>,
+}
+
+impl<'a, T: bytemuck::Pod> TypedAsyncBufferView<'a, T> {
+    fn new(view: AsyncBufferView<'a>) -> Result<Self, MapTypedError> {
+        // Validate the cast eagerly so construction fails fast instead of panicking on the
+        // first `deref`.
+        view.as_slice::<T>()?;
+        Ok(Self {
+            view,
+            _element: PhantomData,
+        })
+    }
+}
+
+impl<'a, T: bytemuck::Pod> Deref for TypedAsyncBufferView<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.view
+            .as_slice()
+            .expect("cast was already validated in `TypedAsyncBufferView::new`")
+    }
+}
+
+/// A mutable typed view over a mapped buffer range, returned by
+/// [`AsyncBuffer::map_async_mut_typed`].
+///
+/// Derefs to `[T]` rather than `[u8]`, so compute readback code can index or iterate the
+/// mapped range without a manual `bytemuck` cast at every use site.
+pub struct TypedAsyncBufferViewMut<'a, T> {
+    view: AsyncBufferViewMut<'a>,
+    _element: PhantomData<T>,
+}
+
+impl<'a, T: bytemuck::Pod> TypedAsyncBufferViewMut<'a, T> {
+    fn new(mut view: AsyncBufferViewMut<'a>) -> Result<Self, MapTypedError> {
+        // Validate the cast eagerly so construction fails fast instead of panicking on the
+        // first `deref`.
+        view.as_mut_slice::<T>()?;
+        Ok(Self {
+            view,
+            _element: PhantomData,
+        })
+    }
+}
+
+impl<'a, T: bytemuck::Pod> Deref for TypedAsyncBufferViewMut<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.view
+            .as_slice()
+            .expect("cast was already validated in `TypedAsyncBufferViewMut::new`")
+    }
+}
+
+impl<'a, T: bytemuck::Pod> DerefMut for TypedAsyncBufferViewMut<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.view
+            .as_mut_slice()
+            .expect("cast was already validated in `TypedAsyncBufferViewMut::new`")
+    }
+}
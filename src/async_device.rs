@@ -0,0 +1,180 @@
+use crate::async_buffer::{AsyncBuffer, OwnedAsyncBufferViewMut};
+use futures::channel::oneshot;
+use std::{
+    ops::Deref,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// A wrapper around a [`wgpu::Device`] which provides `async`-friendly versions of wgpu's
+/// callback-based APIs, such as [`wgpu::BufferSlice::map_async`].
+#[derive(Debug, Clone)]
+pub struct AsyncDevice
+where
+    Self: wgpu::WasmNotSend,
+{
+    device: Arc<wgpu::Device>,
+}
+
+impl AsyncDevice {
+    /// Wraps a device to allow driving its callback-based APIs with `async`.
+    pub fn wrap(device: wgpu::Device) -> Self {
+        Self {
+            device: Arc::new(device),
+        }
+    }
+
+    /// Bridges a callback-based wgpu API into a future.
+    ///
+    /// `register` is called *synchronously, before this function returns* with a callback it
+    /// is responsible for arranging to eventually invoke, e.g. by passing it to
+    /// [`wgpu::BufferSlice::map_async`]. This is deliberately not an `async fn`: if registering
+    /// were deferred to the first `.await`/poll of the returned future, a caller that wanted to
+    /// `poll()` the device for a specific submission between calling `do_async` and awaiting it
+    /// (see [`crate::AsyncBufferSlice::map_async_with_index`]) would poll before wgpu even knew
+    /// about the request, and the awaited future would never resolve.
+    ///
+    /// The returned future resolves with whatever value that callback is invoked with. Note
+    /// that, like the wgpu APIs it wraps, the callback is only invoked in response to the
+    /// device being polled; see [`Self::poll`].
+    pub(crate) fn do_async<T, F>(&self, register: F) -> impl std::future::Future<Output = T>
+    where
+        T: wgpu::WasmNotSend + 'static,
+        F: FnOnce(AsyncCallback<T>),
+    {
+        let (sender, receiver) = oneshot::channel();
+        register(Box::new(move |value| {
+            let _ = sender.send(value);
+        }));
+        async move {
+            receiver
+                .await
+                .expect("callback was dropped without being called")
+        }
+    }
+
+    /// Forwards to [`wgpu::Device::poll`]. This is a plain synchronous call, not a future: with
+    /// a blocking [`wgpu::Maintain`] variant it blocks the calling thread until that wait ends.
+    ///
+    /// Callers with a [`wgpu::SubmissionIndex`] in hand should prefer
+    /// [`wgpu::Maintain::WaitForSubmissionIndex`] over a blanket [`wgpu::Maintain::Wait`], so
+    /// that polling only blocks on the submission that's actually needed.
+    pub fn poll(&self, maintain: wgpu::Maintain) -> wgpu::MaintainResult {
+        self.device.poll(maintain)
+    }
+
+    /// Spawns a background task that repeatedly polls this device with
+    /// [`wgpu::Maintain::Poll`], so that awaiting a mapping (or anything else built on
+    /// [`Self::do_async`]) resolves without the caller having to drive an external poll loop.
+    ///
+    /// The returned [`Poller`] owns the task; drop it to stop polling. On wasm this returns a
+    /// no-op handle, since the browser drives completion itself.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_poller(&self, period: std::time::Duration) -> Poller {
+        let device = self.clone();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        let thread = std::thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                device.poll(wgpu::Maintain::Poll);
+                std::thread::sleep(period);
+            }
+        });
+        Poller {
+            stop,
+            thread: Some(thread),
+        }
+    }
+
+    /// See the native [`Self::spawn_poller`]; on wasm the browser drives completion, so this
+    /// is a no-op that returns an inert handle.
+    #[cfg(target_arch = "wasm32")]
+    pub fn spawn_poller(&self, _period: std::time::Duration) -> Poller {
+        Poller
+    }
+
+    /// Creates a buffer with `mapped_at_creation: true` and immediately returns it alongside a
+    /// writable view over the whole buffer.
+    ///
+    /// Unlike [`AsyncBuffer::map_async_mut`](crate::AsyncBuffer::map_async_mut), no `await` is
+    /// needed: the buffer is already host-visible as soon as it's created. Drop the view (it's
+    /// `'static`, so it can be written to in place before the buffer is used) to unmap the
+    /// buffer and make it ready for GPU use.
+    ///
+    /// `descriptor.mapped_at_creation` is ignored; this always creates the buffer mapped.
+    pub fn create_buffer_mapped(
+        &self,
+        descriptor: &wgpu::BufferDescriptor,
+    ) -> (AsyncBuffer, OwnedAsyncBufferViewMut) {
+        let buffer = Arc::new(self.device.create_buffer(&wgpu::BufferDescriptor {
+            mapped_at_creation: true,
+            ..descriptor.clone()
+        }));
+        let view = OwnedAsyncBufferViewMut::new(buffer.clone(), &buffer.slice(..));
+        (AsyncBuffer::wrap_shared(self.clone(), buffer), view)
+    }
+}
+
+impl Deref for AsyncDevice {
+    type Target = wgpu::Device;
+
+    fn deref(&self) -> &Self::Target {
+        &self.device
+    }
+}
+
+/// The callback type threaded through [`AsyncDevice::do_async`]. `WasmNotSend` isn't an auto
+/// trait, so it can't be named directly in a trait object bound; `Send` is used natively, and
+/// dropped on wasm, where nothing needs to be `Send` in the first place.
+#[cfg(not(target_arch = "wasm32"))]
+type AsyncCallback<T> = Box<dyn FnOnce(T) + Send>;
+#[cfg(target_arch = "wasm32")]
+type AsyncCallback<T> = Box<dyn FnOnce(T)>;
+
+/// A handle to the background polling task started by [`AsyncDevice::spawn_poller`]. Dropping
+/// it stops the task.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct Poller {
+    stop: Arc<AtomicBool>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+// Dropping a `Poller` blocks the calling thread for up to one `period` while it joins the
+// polling thread, so the same "don't do this on a cooperative executor" caveat as
+// `AsyncBufferSlice::map_async_with_index` applies: don't drop one from a task on tokio,
+// async-std, etc.
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for Poller {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// See the native [`Poller`]; on wasm polling is a no-op, so this handle does nothing.
+#[cfg(target_arch = "wasm32")]
+pub struct Poller;
+
+/// Drives `future` to completion on the current thread, interleaving
+/// [`wgpu::Maintain::Wait`] calls on `device` so that `async` mapping calls embedded in it can
+/// resolve without a separate [`AsyncDevice::spawn_poller`] task.
+///
+/// Following Vello's `block_on_wgpu` helper, this is meant for synchronous entry points (e.g.
+/// `main`, or a thread dedicated to GPU work) that need to wait on the result of `future`
+/// without pulling in a full async runtime.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn block_on<F: std::future::Future>(device: &AsyncDevice, future: F) -> F::Output {
+    futures::pin_mut!(future);
+    let waker = futures::task::noop_waker();
+    let mut context = std::task::Context::from_waker(&waker);
+    loop {
+        if let std::task::Poll::Ready(value) = future.as_mut().poll(&mut context) {
+            return value;
+        }
+        device.poll(wgpu::Maintain::Wait);
+    }
+}
@@ -0,0 +1,9 @@
+//! `async`-friendly wrappers around [`wgpu`]'s callback-based APIs.
+
+mod async_buffer;
+mod async_device;
+mod staging_belt;
+
+pub use async_buffer::*;
+pub use async_device::*;
+pub use staging_belt::*;
@@ -0,0 +1,183 @@
+use crate::{
+    async_buffer::{AsyncBuffer, AsyncBufferSlice},
+    async_device::AsyncDevice,
+};
+use std::{
+    ops::{Bound, RangeBounds},
+    sync::{Arc, Mutex},
+};
+use wgpu::BufferAddress;
+
+/// The error returned by [`StagingBelt::read_region`].
+#[derive(Debug)]
+pub enum ReadbackError {
+    /// Mapping the staging buffer failed.
+    Map(wgpu::BufferAsyncError),
+    /// The mapped staging range could not be cast to the requested type.
+    Cast(bytemuck::PodCastError),
+}
+
+impl From<wgpu::BufferAsyncError> for ReadbackError {
+    fn from(error: wgpu::BufferAsyncError) -> Self {
+        Self::Map(error)
+    }
+}
+
+impl From<bytemuck::PodCastError> for ReadbackError {
+    fn from(error: bytemuck::PodCastError) -> Self {
+        Self::Cast(error)
+    }
+}
+
+impl std::fmt::Display for ReadbackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Map(error) => error.fmt(f),
+            Self::Cast(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ReadbackError {}
+
+/// A pool of `MAP_READ | COPY_DST` staging buffers, so that repeated calls to
+/// [`Self::read_region`] reuse buffers of a suitable size rather than allocating a fresh one
+/// every time.
+///
+/// This plays the same role as `wgpu::util::StagingBelt` does for uploads, but for the
+/// readback direction: copy a device buffer into a host-visible staging buffer, map it, and
+/// hand back the bytes, all in one call.
+#[derive(Debug)]
+pub struct StagingBelt {
+    device: AsyncDevice,
+    free_buffers: Mutex<Vec<Arc<wgpu::Buffer>>>,
+}
+
+impl StagingBelt {
+    /// Creates an empty belt. Staging buffers are allocated lazily as [`Self::read_region`] is
+    /// called.
+    pub fn new(device: AsyncDevice) -> Self {
+        Self {
+            device,
+            free_buffers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Copies `bounds` of `source` into a staging buffer, submits that copy on `queue`, awaits
+    /// the mapping, and returns the result as an owned `Vec<T>`.
+    ///
+    /// This is the one-call path from a device buffer to host memory: it replaces manually
+    /// creating a staging buffer, encoding and submitting a `copy_buffer_to_buffer`, awaiting
+    /// the map, and copying out the bytes.
+    pub async fn read_region<T: bytemuck::Pod>(
+        &self,
+        source: &AsyncBuffer,
+        queue: &wgpu::Queue,
+        bounds: impl RangeBounds<BufferAddress>,
+    ) -> Result<Vec<T>, ReadbackError> {
+        let (offset, size) = resolve_range(&bounds, source.size());
+        let staging = self.acquire(size);
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("wgpu-async staging belt readback"),
+            });
+        encoder.copy_buffer_to_buffer(source, offset, &staging, 0, size);
+        queue.submit(Some(encoder.finish()));
+
+        let staging_slice = AsyncBufferSlice::wrap(self.device.clone(), staging.slice(..size));
+        let data = {
+            let view = staging_slice.map_async().await?;
+            view.as_slice::<T>()?.to_vec()
+        };
+
+        self.recycle(staging);
+        Ok(data)
+    }
+
+    fn acquire(&self, size: BufferAddress) -> Arc<wgpu::Buffer> {
+        let mut free_buffers = self.free_buffers.lock().unwrap();
+        if let Some(index) = free_buffers.iter().position(|buffer| buffer.size() >= size) {
+            free_buffers.swap_remove(index)
+        } else {
+            Arc::new(self.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("wgpu-async staging belt buffer"),
+                size,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }))
+        }
+    }
+
+    fn recycle(&self, buffer: Arc<wgpu::Buffer>) {
+        self.free_buffers.lock().unwrap().push(buffer);
+    }
+}
+
+/// Resolves a `RangeBounds<BufferAddress>` against a buffer's total size, the same way
+/// [`wgpu::Buffer::slice`] would, but returning the concrete `(offset, size)` pair rather than
+/// a `BufferSlice`.
+fn resolve_range(
+    bounds: &impl RangeBounds<BufferAddress>,
+    full_size: BufferAddress,
+) -> (BufferAddress, BufferAddress) {
+    let offset = match bounds.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match bounds.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => full_size,
+    };
+    assert!(
+        offset <= end,
+        "range start ({}) is greater than range end ({})",
+        offset,
+        end
+    );
+    (offset, end - offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unbounded() {
+        assert_eq!(resolve_range(&.., 10), (0, 10));
+    }
+
+    #[test]
+    fn exclusive_end() {
+        assert_eq!(resolve_range(&(2..7), 10), (2, 5));
+    }
+
+    #[test]
+    fn inclusive_end() {
+        assert_eq!(resolve_range(&(2..=7), 10), (2, 6));
+    }
+
+    #[test]
+    fn unbounded_start() {
+        assert_eq!(resolve_range(&(..7), 10), (0, 7));
+    }
+
+    #[test]
+    fn unbounded_end() {
+        assert_eq!(resolve_range(&(3..), 10), (3, 7));
+    }
+
+    #[test]
+    fn empty_range() {
+        assert_eq!(resolve_range(&(3..3), 10), (3, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "range start")]
+    fn invalid_range_panics() {
+        resolve_range(&(5..3), 10);
+    }
+}